@@ -1,9 +1,10 @@
 use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
-use ruff_python_ast::{self as ast, Expr, Operator};
+use ruff_python_ast::helpers::any_over_expr;
+use ruff_python_ast::{self as ast, CmpOp, Expr, Operator, UnaryOp};
 
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_codegen::Generator;
-use ruff_text_size::{Ranged, TextRange};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
 use crate::fix::edits::pad;
@@ -61,27 +62,62 @@ pub(crate) fn isinstance_type_none(checker: &mut Checker, call: &ast::ExprCall)
         return;
     };
 
-    if is_none(types) {
-        let Some(Expr::Name(ast::ExprName {
-            id: object_name, ..
-        })) = call.arguments.find_positional(0)
-        else {
-            return;
-        };
-        let mut diagnostic = Diagnostic::new(IsinstanceTypeNone, call.range());
-        let replacement = generate_replacement(object_name, checker.generator());
-        diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-            pad(replacement, call.range(), checker.locator()),
-            call.range(),
-        )));
-        checker.diagnostics.push(diagnostic);
+    if !is_none(types, checker.semantic()) {
+        return;
+    }
+
+    let Some(object) = call.arguments.find_positional(0) else {
+        return;
+    };
+
+    // The object must be a name, or an attribute or subscript chain rooted in one, with no calls
+    // anywhere inside it, so that rewriting it into a comparison doesn't duplicate or drop any
+    // calls. Ex) `isinstance(self.value, type(None))`, `isinstance(d["k"], type(None))`.
+    if !is_simple_object(object) || any_over_expr(object, &Expr::is_call_expr) {
+        return;
+    }
+
+    // Ex) `not isinstance(obj, type(None))` -> `obj is not None`
+    //
+    // In this case, the diagnostic and fix must span the entire `not` expression, rather than
+    // just the `isinstance` call.
+    let (cmp_op, range) = match checker.semantic().current_expression_parent() {
+        Some(Expr::UnaryOp(unary_op @ ast::ExprUnaryOp { op: UnaryOp::Not, .. })) => {
+            (CmpOp::IsNot, unary_op.range())
+        }
+        _ => (CmpOp::Is, call.range()),
+    };
+
+    let mut diagnostic = Diagnostic::new(IsinstanceTypeNone, range);
+    let object_text = checker.locator().slice(object.range());
+    let replacement = generate_replacement(object_text, cmp_op);
+    let edit = Edit::range_replacement(pad(replacement, range, checker.locator()), range);
+    // Re-evaluating a name is always safe, but an attribute or subscript operand may hide a
+    // property or `__getitem__` with side effects, so only offer a safe fix for plain names.
+    diagnostic.set_fix(if matches!(object, Expr::Name(_)) {
+        Fix::safe_edit(edit)
+    } else {
+        Fix::unsafe_edit(edit)
+    });
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns `true` if `expr` is a name, or an attribute/subscript chain rooted in one (e.g.
+/// `self.value` or `d["k"].value`). A subscript's slice is not inspected, so `d[f()]` also
+/// returns `true`.
+pub(super) fn is_simple_object(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(_) => true,
+        Expr::Attribute(ast::ExprAttribute { value, .. }) => is_simple_object(value),
+        Expr::Subscript(ast::ExprSubscript { value, .. }) => is_simple_object(value),
+        _ => false,
     }
 }
 
 /// Returns `true` if the given expression is equivalent to checking if the
 /// object type is `None` when used with the `isinstance` builtin.
-fn is_none(expr: &Expr) -> bool {
-    fn inner(expr: &Expr, in_union_context: bool) -> bool {
+pub(super) fn is_none(expr: &Expr, semantic: &SemanticModel) -> bool {
+    fn inner(expr: &Expr, in_union_context: bool, semantic: &SemanticModel) -> bool {
         match expr {
             // Ex) `None`
             // Note: `isinstance` only accepts `None` as a type when used with
@@ -89,23 +125,12 @@ fn is_none(expr: &Expr) -> bool {
             Expr::Constant(ast::ExprConstant { value, .. }) if in_union_context => value.is_none(),
 
             // Ex) `type(None)`
-            Expr::Call(ast::ExprCall {
-                func, arguments, ..
-            }) if arguments.len() == 1 => {
-                if let Expr::Name(ast::ExprName { id, .. }) = func.as_ref() {
-                    if id.as_str() == "type" {
-                        if let Some(Expr::Constant(ast::ExprConstant { value, .. })) =
-                            arguments.args.get(0)
-                        {
-                            return value.is_none();
-                        }
-                    }
-                }
-                false
-            }
+            Expr::Call(_) => is_type_none_call(expr),
 
             // Ex) `(type(None),)`
-            Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().all(|elt| inner(elt, false)),
+            Expr::Tuple(ast::ExprTuple { elts, .. }) => {
+                elts.iter().all(|elt| inner(elt, false, semantic))
+            }
 
             // Ex) `type(None) | type(None)`
             Expr::BinOp(ast::ExprBinOp {
@@ -113,32 +138,75 @@ fn is_none(expr: &Expr) -> bool {
                 op: Operator::BitOr,
                 right,
                 ..
-            }) => inner(left, true) && inner(right, true),
+            }) => inner(left, true, semantic) && inner(right, true, semantic),
+
+            // Ex) `types.NoneType`
+            Expr::Attribute(_) => is_none_type(expr, semantic),
+
+            // Ex) `from types import NoneType; ...; NoneType`
+            Expr::Name(_) => is_none_type(expr, semantic),
 
             // Otherwise, return false.
             _ => false,
         }
     }
-    inner(expr, false)
+    inner(expr, false, semantic)
+}
+
+/// Returns `true` if `expr` resolves to `types.NoneType`.
+pub(super) fn is_none_type(expr: &Expr, semantic: &SemanticModel) -> bool {
+    semantic
+        .resolve_qualified_name(expr)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["types", "NoneType"]))
 }
 
-/// Format a code snippet comparing `name` to `None` (e.g., `name is None`).
-fn generate_replacement(name: &str, generator: Generator) -> String {
-    // Construct `name`.
-    let var = ast::ExprName {
-        id: name.to_string(),
-        ctx: ast::ExprContext::Load,
-        range: TextRange::default(),
+/// Returns `true` if `expr` is a call to `type(None)`.
+fn is_type_none_call(expr: &Expr) -> bool {
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = expr
+    else {
+        return false;
+    };
+    if arguments.len() != 1 {
+        return false;
+    }
+    let Expr::Name(ast::ExprName { id, .. }) = func.as_ref() else {
+        return false;
+    };
+    if id.as_str() != "type" {
+        return false;
+    }
+    let Some(Expr::Constant(ast::ExprConstant { value, .. })) = arguments.args.get(0) else {
+        return false;
     };
-    // Construct `name is None`.
-    let compare = ast::ExprCompare {
-        left: Box::new(var.into()),
-        ops: vec![ast::CmpOp::Is],
-        comparators: vec![ast::Expr::Constant(ast::ExprConstant {
-            value: ast::Constant::None,
-            range: TextRange::default(),
-        })],
-        range: TextRange::default(),
+    value.is_none()
+}
+
+/// Returns `true` if `expr` is one of the scalar spellings of "the type of `None`": `type(None)`,
+/// `types.NoneType`, or an imported `NoneType` name. Unlike [`is_none`], this does not recurse
+/// into tuples or `|` unions: those are only meaningful as the second argument to `isinstance`,
+/// and a `type` object is never `==`/`is` a tuple or a `UnionType` instance the way it is to
+/// another `type` object.
+pub(super) fn is_none_type_scalar(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        // Ex) `type(None)`
+        Expr::Call(_) => is_type_none_call(expr),
+
+        // Ex) `types.NoneType`, or `from types import NoneType; ...; NoneType`
+        Expr::Attribute(_) | Expr::Name(_) => is_none_type(expr, semantic),
+
+        // Otherwise, return false.
+        _ => false,
+    }
+}
+
+/// Format a code snippet comparing the source text of an object to `None` (e.g., `obj is None`,
+/// `obj is not None`).
+pub(super) fn generate_replacement(object: &str, cmp_op: CmpOp) -> String {
+    let op = match cmp_op {
+        CmpOp::IsNot => "is not",
+        _ => "is",
     };
-    generator.expr(&compare.into())
+    format!("{object} {op} None")
 }
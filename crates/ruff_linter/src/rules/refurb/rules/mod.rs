@@ -0,0 +1,5 @@
+pub(crate) use isinstance_type_none::*;
+pub(crate) use type_none_comparison::*;
+
+mod isinstance_type_none;
+mod type_none_comparison;
@@ -0,0 +1,117 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_python_ast::helpers::any_over_expr;
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+
+use ruff_macros::{derive_message_formats, violation};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::fix::edits::pad;
+
+use super::isinstance_type_none::{generate_replacement, is_none_type_scalar, is_simple_object};
+
+/// ## What it does
+/// Checks for equality and identity comparisons of `type()` calls against
+/// the type of `None`.
+///
+/// ## Why is this bad?
+/// There is only ever one instance of `None`, so it is more efficient and
+/// readable to use the `is` operator to check if an object is `None`,
+/// rather than comparing its type to the type of `None`.
+///
+/// ## Example
+/// ```python
+/// type(obj) == type(None)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// obj is None
+/// ```
+///
+/// ## References
+/// - [Python documentation: `None`](https://docs.python.org/3/library/constants.html#None)
+/// - [Python documentation: `type`](https://docs.python.org/3/library/functions.html#type)
+/// - [Python documentation: Identity comparisons](https://docs.python.org/3/reference/expressions.html#is-not)
+#[violation]
+pub struct TypeNoneComparison;
+
+impl Violation for TypeNoneComparison {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Prefer `is` operator over `type` comparison to check if an object is `None`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with `is` operator".to_string())
+    }
+}
+
+/// FURB169
+pub(crate) fn type_none_comparison(checker: &mut Checker, compare: &ast::ExprCompare) {
+    let [op] = compare.ops.as_slice() else {
+        return;
+    };
+    if !matches!(op, CmpOp::Eq | CmpOp::Is) {
+        return;
+    }
+    let [right] = compare.comparators.as_slice() else {
+        return;
+    };
+
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = compare.left.as_ref()
+    else {
+        return;
+    };
+    if arguments.len() != 1 {
+        return;
+    }
+    let Expr::Name(ast::ExprName { id, .. }) = func.as_ref() else {
+        return;
+    };
+    if id.as_str() != "type" {
+        return;
+    }
+    if !checker.semantic().is_builtin(id) {
+        return;
+    }
+
+    // Note: unlike `isinstance`'s second argument, `right` is a single comparator here, not a
+    // type or a tuple/union of types, so only the scalar spellings of "the type of `None`" are
+    // ever actually equal (or identical) to a `type` object.
+    if !is_none_type_scalar(right, checker.semantic()) {
+        return;
+    }
+
+    let Some(object) = arguments.args.get(0) else {
+        return;
+    };
+
+    // As in `isinstance_type_none`, the object must be a name, or an attribute or subscript chain
+    // rooted in one, with no calls anywhere inside it, so that rewriting it into a comparison
+    // doesn't duplicate or drop any calls.
+    // Ex) `type(self.value) == type(None)`, `type(d["k"]) == type(None)`.
+    if !is_simple_object(object) || any_over_expr(object, &Expr::is_call_expr) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(TypeNoneComparison, compare.range());
+    let object_text = checker.locator().slice(object.range());
+    let replacement = generate_replacement(object_text, CmpOp::Is);
+    let edit = Edit::range_replacement(
+        pad(replacement, compare.range(), checker.locator()),
+        compare.range(),
+    );
+    // Re-evaluating a name is always safe, but an attribute or subscript operand may hide a
+    // property or `__getitem__` with side effects, so only offer a safe fix for plain names.
+    diagnostic.set_fix(if matches!(object, Expr::Name(_)) {
+        Fix::safe_edit(edit)
+    } else {
+        Fix::unsafe_edit(edit)
+    });
+    checker.diagnostics.push(diagnostic);
+}